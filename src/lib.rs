@@ -22,7 +22,39 @@ use near_contract_standards::fungible_token::metadata::{
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
 use near_sdk::collections::{LazyOption, UnorderedMap};
 use near_sdk::json_types::U128;
-use near_sdk::{env, near_bindgen, AccountId, Balance, PanicOnDefault};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{
+    assert_one_yocto, env, ext_contract, near_bindgen, AccountId, Balance, Gas, PanicOnDefault,
+    Promise, PromiseOrValue, PromiseResult, StorageUsage,
+};
+
+/// Amount of gas reserved for the `ft_resolve_transfer` callback.
+const GAS_FOR_RESOLVE_TRANSFER: Gas = Gas(5_000_000_000_000);
+/// Amount of gas reserved for `ft_transfer_call` itself, including the gas forwarded to
+/// `ft_resolve_transfer`. Whatever prepaid gas remains is forwarded to the receiver.
+const GAS_FOR_FT_TRANSFER_CALL: Gas = Gas(25_000_000_000_000 + GAS_FOR_RESOLVE_TRANSFER.0);
+
+/// Cross-contract call made to the receiver of an `ft_transfer_call`.
+#[ext_contract(ext_ft_receiver)]
+pub trait FungibleTokenReceiver {
+    fn ft_on_transfer(
+        &mut self,
+        sender_id: AccountId,
+        amount: U128,
+        msg: String,
+    ) -> PromiseOrValue<U128>;
+}
+
+/// Resolver callback invoked on this contract once the receiver's `ft_on_transfer` settles.
+#[ext_contract(ext_self)]
+pub trait FungibleTokenResolver {
+    fn ft_resolve_transfer(
+        &mut self,
+        sender_id: AccountId,
+        receiver_id: AccountId,
+        amount: U128,
+    ) -> U128;
+}
 
 #[near_bindgen]
 #[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
@@ -30,6 +62,11 @@ pub struct Contract {
     metadata: LazyOption<FungibleTokenMetadata>,
     fundAccounts: UnorderedMap<String, Balance>,
     accounts: UnorderedMap<AccountId, Balance>,
+    storage_balances: UnorderedMap<AccountId, StorageBalance>,
+    /// Bytes of trie storage consumed by registering a single account, measured once at
+    /// initialization. Used to derive the NEP-145 storage balance bounds.
+    bytes_for_longest_account_id: StorageUsage,
+    total_supply: Balance,
     canMint: bool,
     canBurn: bool,
     ownerId: AccountId,
@@ -61,12 +98,35 @@ impl Contract {
             metadata: LazyOption::new(b"m".to_vec(), Some(&metadata)),
             accounts: UnorderedMap::new(b"a".to_vec()),
             fundAccounts: UnorderedMap::new(b"f".to_vec()),
+            storage_balances: UnorderedMap::new(b"s".to_vec()),
+            bytes_for_longest_account_id: 0,
+            total_supply: total_supply.into(),
             canBurn: can_burn,
             canMint: can_mint,
             ownerId: owner_id.clone(),
             glueId: glue_id,
         };
+
+        // Measure the trie bytes a freshly registered account consumes so
+        // `storage_balance_bounds` can derive an accurate minimum deposit. A real registration
+        // via `storage_deposit` writes into both `accounts` and `storage_balances`, so the probe
+        // must touch both or it undercharges new accounts.
+        let initial_storage_usage = env::storage_usage();
+        let tmp_account_id: AccountId = "a".repeat(64).parse().unwrap();
+        this.accounts.insert(&tmp_account_id, &0u128);
+        this.storage_balances.insert(
+            &tmp_account_id,
+            &StorageBalance {
+                total: 0.into(),
+                available: 0.into(),
+            },
+        );
+        this.bytes_for_longest_account_id = env::storage_usage() - initial_storage_usage;
+        this.accounts.remove(&tmp_account_id);
+        this.storage_balances.remove(&tmp_account_id);
+
         this.accounts.insert(&owner_id, &u128::from(total_supply));
+        this.internal_register_account(&owner_id);
         FtMint {
             owner_id: &owner_id,
             amount: &total_supply,
@@ -77,9 +137,16 @@ impl Contract {
     }
 
     fn internal_deposit(&mut self, account: &AccountId, amount: u128) {
+        assert!(
+            self.storage_balances.contains_key(account),
+            "The account {} is not registered",
+            account
+        );
         let balance = self.accounts.get(account).unwrap_or(0);
-        self.accounts.insert(&account, &(balance + amount));
-        
+        let new_balance = balance
+            .checked_add(amount)
+            .unwrap_or_else(|| env::panic_str("Balance overflow"));
+        self.accounts.insert(&account, &new_balance);
     }
     fn internal_withdraw(&mut self, account: &AccountId, amount: u128) {
         let balance = self.accounts.get(account).unwrap_or(0);
@@ -87,15 +154,46 @@ impl Contract {
         self.accounts.insert(&account, &(balance - amount));
     }
 
+    /// Bytes-based minimum storage balance required to register an account, in yoctoNEAR.
+    fn required_storage_balance(&self) -> Balance {
+        Balance::from(self.bytes_for_longest_account_id) * env::storage_byte_cost()
+    }
+
+    /// Registers `account_id` for free with the minimum required storage balance. Used for the
+    /// owner account at initialization; all other accounts must go through [`storage_deposit`].
+    fn internal_register_account(&mut self, account_id: &AccountId) {
+        self.storage_balances.insert(
+            account_id,
+            &StorageBalance {
+                total: self.required_storage_balance().into(),
+                available: 0.into(),
+            },
+        );
+    }
+
     pub fn burnToken(&mut self, amount: U128) {
         assert!(self.canBurn);
-        assert!(env::signer_account_id() == self.ownerId);
+        assert!(env::predecessor_account_id() == self.ownerId);
         self.internal_withdraw(&self.ownerId.clone(), amount.into());
+        self.total_supply = self
+            .total_supply
+            .checked_sub(amount.into())
+            .unwrap_or_else(|| env::panic_str("Total supply overflow"));
+        FtBurn {
+            owner_id: &self.ownerId,
+            amount: &amount,
+            memo: Some("burned"),
+        }
+        .emit();
     }
     pub fn mintToken(&mut self, amount: U128) {
         assert!(self.canMint);
-        assert!(env::signer_account_id() == self.ownerId);
+        assert!(env::predecessor_account_id() == self.ownerId);
         self.internal_deposit(&self.ownerId.clone(), amount.into());
+        self.total_supply = self
+            .total_supply
+            .checked_add(amount.into())
+            .unwrap_or_else(|| env::panic_str("Total supply overflow"));
     }
 
     // pub fn sendToken()
@@ -105,8 +203,10 @@ impl Contract {
     // //     this.internalTransfer(this.owner, walletAddress, amount, "")
     // // }
 
+    #[payable]
     pub fn ft_transfer(&mut self, receiver_id: AccountId, amount: U128) {
-        let sender = env::signer_account_id();
+        assert_one_yocto();
+        let sender = env::predecessor_account_id();
         self.internal_withdraw(&sender, amount.into());
         self.internal_deposit(&receiver_id, amount.into());
         FtTransfer {
@@ -118,24 +218,118 @@ impl Contract {
         .emit();
     }
 
+    /// Transfers `amount` to `receiver_id` and then calls `ft_on_transfer` on it, allowing the
+    /// receiver to act on the deposit (e.g. a DEX or vault) before the transfer is finalized.
+    /// Any amount the receiver reports as unused is refunded back to the sender.
+    #[payable]
+    pub fn ft_transfer_call(
+        &mut self,
+        receiver_id: AccountId,
+        amount: U128,
+        memo: Option<String>,
+        msg: String,
+    ) -> PromiseOrValue<U128> {
+        assert_one_yocto();
+        let sender = env::predecessor_account_id();
+        self.internal_withdraw(&sender, amount.into());
+        self.internal_deposit(&receiver_id, amount.into());
+        FtTransfer {
+            old_owner_id: &sender,
+            new_owner_id: &receiver_id,
+            amount: &amount,
+            memo: memo.as_deref(),
+        }
+        .emit();
+
+        let gas_for_ft_on_transfer = env::prepaid_gas()
+            .0
+            .checked_sub(GAS_FOR_FT_TRANSFER_CALL.0)
+            .unwrap_or_else(|| env::panic_str("Prepaid gas overflow"));
+
+        ext_ft_receiver::ft_on_transfer(
+            sender.clone(),
+            amount,
+            msg,
+            receiver_id.clone(),
+            0,
+            Gas(gas_for_ft_on_transfer),
+        )
+        .then(ext_self::ft_resolve_transfer(
+            sender,
+            receiver_id,
+            amount,
+            env::current_account_id(),
+            0,
+            GAS_FOR_RESOLVE_TRANSFER,
+        ))
+        .into()
+    }
+
+    /// Callback for [`ft_transfer_call`](Contract::ft_transfer_call). Reads how much of the
+    /// transfer the receiver left unused and refunds that portion to the sender, returning the
+    /// amount that was actually used.
+    #[private]
+    pub fn ft_resolve_transfer(
+        &mut self,
+        sender_id: AccountId,
+        receiver_id: AccountId,
+        amount: U128,
+    ) -> U128 {
+        let amount: Balance = amount.into();
+
+        let unused_amount = match env::promise_result(0) {
+            PromiseResult::NotReady => env::abort(),
+            PromiseResult::Successful(value) => {
+                if let Ok(unused_amount) = near_sdk::serde_json::from_slice::<U128>(&value) {
+                    std::cmp::min(amount, unused_amount.0)
+                } else {
+                    amount
+                }
+            }
+            PromiseResult::Failed => amount,
+        };
+
+        if unused_amount > 0 {
+            let receiver_balance = self.accounts.get(&receiver_id).unwrap_or(0);
+            if receiver_balance > 0 {
+                let refund_amount = std::cmp::min(receiver_balance, unused_amount);
+                self.internal_withdraw(&receiver_id, refund_amount);
+                self.internal_deposit(&sender_id, refund_amount);
+                FtTransfer {
+                    old_owner_id: &receiver_id,
+                    new_owner_id: &sender_id,
+                    amount: &U128(refund_amount),
+                    memo: Some("refund"),
+                }
+                .emit();
+                return U128(amount - refund_amount);
+            }
+        }
+        U128(amount)
+    }
+
     // add to users tokens to fund map
     pub fn sendToFund(&mut self, id: String, amount: U128) {
-        let signer = env::signer_account_id();
+        let signer = env::predecessor_account_id();
         assert!(signer == self.ownerId || signer == self.glueId);
         self.internal_withdraw(&self.ownerId.clone(), amount.into());
         let balance: u128 = self.fundAccounts.get(&id).unwrap_or(0);
-        let new_balance: u128 = balance + u128::from(amount);
+        let new_balance: u128 = balance
+            .checked_add(amount.into())
+            .unwrap_or_else(|| env::panic_str("Balance overflow"));
         self.fundAccounts.insert(&id, &new_balance);
     }
 
     // claims tokens from fund to users web3 account
     pub fn sendFromFund(&mut self, id: String, walletAddress: AccountId, amount: U128) {
-        let signer = env::signer_account_id();
+        let signer = env::predecessor_account_id();
         assert!(signer == self.ownerId || signer == self.glueId);
         let balance = self.fundAccounts.get(&id).unwrap_or(0);
         let amountInt = u128::from(amount);
-        assert!(balance >= amountInt);
-        self.fundAccounts.insert(&id, &(balance - amountInt));
+        let new_balance = balance
+            .checked_sub(amountInt)
+            .unwrap_or_else(|| env::panic_str("Balance overflow"));
+        self.fundAccounts.insert(&id, &new_balance);
         self.internal_deposit(&walletAddress, amountInt);
         FtTransfer {
             old_owner_id: &self.ownerId,
@@ -146,8 +340,35 @@ impl Contract {
         .emit();
     }
 
+    /// Claims tokens from several funds to several wallets in one call, logging a single
+    /// NEP-297 event with one entry per transfer instead of one log per transfer. Intended for
+    /// airdrop-style payouts where per-transfer logging would otherwise dominate gas costs.
+    pub fn sendFromFundBatch(&mut self, transfers: Vec<(String, AccountId, U128)>) {
+        let signer = env::predecessor_account_id();
+        assert!(signer == self.ownerId || signer == self.glueId);
+        let owner_id = self.ownerId.clone();
+
+        let mut events: Vec<FtTransfer> = Vec::with_capacity(transfers.len());
+        for (id, wallet_address, amount) in &transfers {
+            let balance = self.fundAccounts.get(id).unwrap_or(0);
+            let amount_int = u128::from(*amount);
+            let new_balance = balance
+                .checked_sub(amount_int)
+                .unwrap_or_else(|| env::panic_str("Balance overflow"));
+            self.fundAccounts.insert(id, &new_balance);
+            self.internal_deposit(wallet_address, amount_int);
+            events.push(FtTransfer {
+                old_owner_id: &owner_id,
+                new_owner_id: wallet_address,
+                amount,
+                memo: Some("transfered"),
+            });
+        }
+        FtTransfer::emit_many(&events);
+    }
+
     pub fn changeOwner(&mut self, address: AccountId) {
-        assert!(env::signer_account_id() == self.ownerId);
+        assert!(env::predecessor_account_id() == self.ownerId);
         self.ownerId = address;
     }
 
@@ -156,6 +377,10 @@ impl Contract {
         U128::from(balance)
     }
 
+    pub fn ft_total_supply(&self) -> U128 {
+        U128::from(self.total_supply)
+    }
+
     pub fn ft_fund_balance_of(&self, account_id: String) -> U128 {
         let balance = self.fundAccounts.get(&account_id).unwrap_or(0);
         U128::from(balance)
@@ -195,6 +420,153 @@ impl FungibleTokenMetadataProvider for Contract {
     }
 }
 
+// Storage management (NEP-145).
+//
+// <https://github.com/near/NEPs/blob/master/specs/Standards/StorageManagement.md>
+//
+// Every account must pay for the trie storage its balance entry consumes before it can send or
+// receive tokens. This is what actually backs the storage-cost notes in the module header: the
+// bounds are derived once, at init, from the bytes a single account registration consumes.
+
+#[derive(Serialize, Deserialize, BorshDeserialize, BorshSerialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct StorageBalance {
+    pub total: U128,
+    pub available: U128,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct StorageBalanceBounds {
+    pub min: U128,
+    pub max: Option<U128>,
+}
+
+#[near_bindgen]
+impl Contract {
+    /// Registers `account_id` (default: the caller) so it can hold a balance, or tops up its
+    /// storage balance if it is already registered. By default the whole deposit is kept and
+    /// anything over the minimum becomes available storage balance; with `registration_only`
+    /// set, the deposit over the minimum (all of it, for an already registered account) is
+    /// refunded.
+    #[payable]
+    pub fn storage_deposit(
+        &mut self,
+        account_id: Option<AccountId>,
+        registration_only: Option<bool>,
+    ) -> StorageBalance {
+        let amount: Balance = env::attached_deposit();
+        let account_id = account_id.unwrap_or_else(env::predecessor_account_id);
+        let registration_only = registration_only.unwrap_or(false);
+        let min_balance = self.required_storage_balance();
+
+        if let Some(mut balance) = self.storage_balances.get(&account_id) {
+            if amount > 0 {
+                if registration_only {
+                    Promise::new(env::predecessor_account_id()).transfer(amount);
+                } else {
+                    balance.total = (balance.total.0 + amount).into();
+                    balance.available = (balance.available.0 + amount).into();
+                    self.storage_balances.insert(&account_id, &balance);
+                }
+            }
+            balance
+        } else {
+            assert!(
+                amount >= min_balance,
+                "The attached deposit is less than the minimum storage balance"
+            );
+            self.accounts.insert(&account_id, &0u128);
+            let excess = amount - min_balance;
+            let balance = if registration_only {
+                if excess > 0 {
+                    Promise::new(env::predecessor_account_id()).transfer(excess);
+                }
+                StorageBalance {
+                    total: min_balance.into(),
+                    available: 0.into(),
+                }
+            } else {
+                StorageBalance {
+                    total: amount.into(),
+                    available: excess.into(),
+                }
+            };
+            self.storage_balances.insert(&account_id, &balance);
+            balance
+        }
+    }
+
+    /// Withdraws up to `amount` (default: all) of the caller's *available* storage balance.
+    /// Requires exactly one yoctoNEAR attached, per the storage management spec.
+    #[payable]
+    pub fn storage_withdraw(&mut self, amount: Option<U128>) -> StorageBalance {
+        assert_one_yocto();
+        let account_id = env::predecessor_account_id();
+        let mut balance = self
+            .storage_balances
+            .get(&account_id)
+            .unwrap_or_else(|| env::panic_str("The account is not registered"));
+        let amount: Balance = amount.map(|a| a.0).unwrap_or(balance.available.0);
+        assert!(
+            amount <= balance.available.0,
+            "Cannot withdraw more than the available storage balance"
+        );
+        balance.total = (balance.total.0 - amount).into();
+        balance.available = (balance.available.0 - amount).into();
+        self.storage_balances.insert(&account_id, &balance);
+        Promise::new(account_id).transfer(amount);
+        balance
+    }
+
+    /// Unregisters the caller, releasing its storage deposit. If the account still holds a
+    /// token balance this fails unless `force` is set, in which case the remaining balance is
+    /// burned. Requires exactly one yoctoNEAR attached.
+    #[payable]
+    pub fn storage_unregister(&mut self, force: Option<bool>) -> bool {
+        assert_one_yocto();
+        let account_id = env::predecessor_account_id();
+        if let Some(balance) = self.storage_balances.get(&account_id) {
+            let force = force.unwrap_or(false);
+            let token_balance = self.accounts.get(&account_id).unwrap_or(0);
+            assert!(
+                token_balance == 0 || force,
+                "Can't unregister the account with a positive balance without force"
+            );
+            self.storage_balances.remove(&account_id);
+            self.accounts.remove(&account_id);
+            if token_balance > 0 {
+                self.total_supply = self
+                    .total_supply
+                    .checked_sub(token_balance)
+                    .unwrap_or_else(|| env::panic_str("Total supply overflow"));
+                FtBurn {
+                    owner_id: &account_id,
+                    amount: &U128(token_balance),
+                    memo: Some("storage unregister"),
+                }
+                .emit();
+            }
+            Promise::new(account_id).transfer(balance.total.0);
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn storage_balance_of(&self, account_id: AccountId) -> Option<StorageBalance> {
+        self.storage_balances.get(&account_id)
+    }
+
+    pub fn storage_balance_bounds(&self) -> StorageBalanceBounds {
+        let required = self.required_storage_balance();
+        StorageBalanceBounds {
+            min: required.into(),
+            max: Some(required.into()),
+        }
+    }
+}
+
 
 //ß Standard for nep141 (Fungible Token) events.
 //ß
@@ -207,40 +579,73 @@ impl FungibleTokenMetadataProvider for Contract {
 //ß
 //ß The three events in this standard are [`FtMint`], [`FtTransfer`], and [`FtBurn`].
 //ß
-//ß These events can be logged by calling `.emit()` on them if a single event, or calling
-//ß [`FtMint::emit_many`], [`FtTransfer::emit_many`],
-//ß or [`FtBurn::emit_many`] respectively.
-
-use near_sdk::serde::Serialize;
-
-#[derive(Serialize, Debug)]
-#[serde(tag = "standard")]
-#[must_use = "don't forget to `.emit()` this event"]
-#[serde(rename_all = "snake_case")]
-pub(crate) enum NearEvent<'a> {
-    Nep141(Nep141Event<'a>),
-}
-
-impl<'a> NearEvent<'a> {
-    fn to_json_string(&self) -> String {
-        // Events cannot fail to serialize so fine to panic on error
-        #[allow(clippy::redundant_closure)]
-        serde_json::to_string(self).ok().unwrap_or_else(|| env::abort())
-    }
-
-    fn to_json_event_string(&self) -> String {
-        format!("EVENT_JSON:{}", self.to_json_string())
+//ß These events can be logged by calling `.emit()` on them if a single event, or
+//ß `Type::emit_many(&[...])` for a batch of events of the same type.
+//ß
+//ß Each event type implements this generically through the [`Event`] trait below instead of
+//ß hand-wiring a `NearEvent`/`EventKind` enum per standard, so a new event kind (e.g. a
+//ß `FundDeposit`/`FundClaim` pair for the `fundAccounts` flows) only needs a struct definition
+//ß and one `event!` macro line.
+
+/// A NEP-297 event: something that serializes to `{ "standard", "version", "event", "data" }`
+/// and logs itself with the `EVENT_JSON:` prefix the NEAR indexer watches for.
+///
+/// `STANDARD`/`VERSION` are the event's NEP standard and version, and `event_name()` is the
+/// `event` field value (e.g. `"ft_mint"`). Implement this via the [`event!`] macro rather than
+/// by hand.
+pub trait Event: Serialize + Sized {
+    const STANDARD: &'static str;
+    const VERSION: &'static str;
+
+    fn event_name() -> &'static str;
+
+    /// Logs a single event to the host.
+    fn emit(&self) {
+        Self::emit_many(std::slice::from_ref(self));
     }
 
-    /// Logs the event to the host. This is required to ensure that the event is triggered
-    /// and to consume the event.
-    pub(crate) fn emit(self) {
-        near_sdk::env::log_str(&self.to_json_event_string());
+    /// Logs a batch of events of the same kind as one `EVENT_JSON:` line, where `data` holds
+    /// one entry per event.
+    fn emit_many(data: &[Self]) {
+        #[derive(Serialize)]
+        #[serde(crate = "near_sdk::serde")]
+        struct EventJson<'a, T> {
+            standard: &'static str,
+            version: &'static str,
+            event: &'static str,
+            data: &'a [T],
+        }
+        let payload = EventJson {
+            standard: Self::STANDARD,
+            version: Self::VERSION,
+            event: Self::event_name(),
+            data,
+        };
+        // Events cannot fail to serialize so fine to panic on error.
+        let serialized = near_sdk::serde_json::to_string(&payload)
+            .ok()
+            .unwrap_or_else(|| env::abort());
+        env::log_str(&format!("EVENT_JSON:{}", serialized));
     }
 }
 
+/// Implements [`Event`] for `$ty<'_>`. The closest thing to a `#[event(standard, version)]`
+/// derive that a single-crate setup can offer: attribute macros must live in a separate
+/// `proc-macro` crate, which this crate doesn't have, so a declarative macro stands in for it.
+macro_rules! event {
+    ($ty:ident, standard = $standard:literal, version = $version:literal, event = $name:literal) => {
+        impl Event for $ty<'_> {
+            const STANDARD: &'static str = $standard;
+            const VERSION: &'static str = $version;
+
+            fn event_name() -> &'static str {
+                $name
+            }
+        }
+    };
+}
 
-/// Data to log for an FT mint event. To log this event, call [`.emit()`](FtMint::emit).
+/// Data to log for an FT mint event. To log this event, call [`.emit()`](Event::emit).
 #[must_use]
 #[derive(Serialize, Debug, Clone)]
 pub struct FtMint<'a> {
@@ -250,22 +655,9 @@ pub struct FtMint<'a> {
     pub memo: Option<&'a str>,
 }
 
-impl FtMint<'_> {
-    /// Logs the event to the host. This is required to ensure that the event is triggered
-    /// and to consume the event.
-    pub fn emit(self) {
-        Self::emit_many(&[self])
-    }
-
-    /// Emits an FT mint event, through [`env::log_str`](near_sdk::env::log_str),
-    /// where each [`FtMint`] represents the data of each mint.
-    pub fn emit_many(data: &[FtMint<'_>]) {
-        new_141_v1(Nep141EventKind::FtMint(data)).emit()
-    }
-}
+event!(FtMint, standard = "nep141", version = "1.0.0", event = "ft_mint");
 
-/// Data to log for an FT transfer event. To log this event,
-/// call [`.emit()`](FtTransfer::emit).
+/// Data to log for an FT transfer event. To log this event, call [`.emit()`](Event::emit).
 #[must_use]
 #[derive(Serialize, Debug, Clone)]
 pub struct FtTransfer<'a> {
@@ -276,40 +668,16 @@ pub struct FtTransfer<'a> {
     pub memo: Option<&'a str>,
 }
 
-impl FtTransfer<'_> {
-    /// Logs the event to the host. This is required to ensure that the event is triggered
-    /// and to consume the event.
-    pub fn emit(self) {
-        Self::emit_many(&[self])
-    }
+event!(FtTransfer, standard = "nep141", version = "1.0.0", event = "ft_transfer");
 
-    /// Emits an FT transfer event, through [`env::log_str`](near_sdk::env::log_str),
-    /// where each [`FtTransfer`] represents the data of each transfer.
-    pub fn emit_many(data: &[FtTransfer<'_>]) {
-        new_141_v1(Nep141EventKind::FtTransfer(data)).emit()
-    }
-}
-
-#[derive(Serialize, Debug)]
-pub(crate) struct Nep141Event<'a> {
-    version: &'static str,
-    #[serde(flatten)]
-    event_kind: Nep141EventKind<'a>,
-}
-
-#[derive(Serialize, Debug)]
-#[serde(tag = "event", content = "data")]
-#[serde(rename_all = "snake_case")]
-#[allow(clippy::enum_variant_names)]
-enum Nep141EventKind<'a> {
-    FtMint(&'a [FtMint<'a>]),
-    FtTransfer(&'a [FtTransfer<'a>]),
-}
-
-fn new_141<'a>(version: &'static str, event_kind: Nep141EventKind<'a>) -> NearEvent<'a> {
-    NearEvent::Nep141(Nep141Event { version, event_kind })
+/// Data to log for an FT burn event. To log this event, call [`.emit()`](Event::emit).
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct FtBurn<'a> {
+    pub owner_id: &'a AccountId,
+    pub amount: &'a U128,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<&'a str>,
 }
 
-fn new_141_v1(event_kind: Nep141EventKind) -> NearEvent {
-    new_141("1.0.0", event_kind)
-}
+event!(FtBurn, standard = "nep141", version = "1.0.0", event = "ft_burn");